@@ -119,7 +119,7 @@ fn setup(mut commands: Commands) {
     commands.spawn((
         Actor { name: "Paul" },
         MaxHealth::default(),
-        ExtraMaxHealthCharm::default(),
+        ExtraMaxHealthCharm,
         Health::default(),
         RegenRate(2),
     ));
@@ -5,13 +5,29 @@
 //!
 //! See the [`Attribute`] trait for a more detailed overview, or view [the examples].
 //!
+//! Enabling the `test-util` feature adds the [`test`] module, with helpers for testing attributes.
+//!
 //! [the examples]: https://github.com/istanbul-not-constantinople/bevy_attr/tree/main/examples
 
 use core::fmt;
-use std::{cmp::Ordering, marker::PhantomData};
+use std::{
+    any::{Any, TypeId},
+    cmp::Ordering,
+    marker::PhantomData,
+};
+
+use bevy::{
+    ecs::{
+        schedule::{ShouldRun, Stage, SystemDescriptor},
+        system::AsSystemLabel,
+    },
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use bevy_trait_query::{ReadTraits, RegisterExt};
 
-use bevy::prelude::*;
-use bevy_trait_query::RegisterExt;
+#[cfg(feature = "test-util")]
+pub mod test;
 
 /// Resets a variable to its default value.
 ///
@@ -48,7 +64,7 @@ impl<T: Default> Reset for T {
     }
 }
 
-/// Marker trait for components which act as attributes.
+/// Trait for components which act as attributes.
 ///
 /// An attribute has a base value (defined by the [`Reset`] trait)
 /// and a number of [modifiers][Modifier] which are attached to the same entity.
@@ -58,6 +74,7 @@ impl<T: Default> Reset for T {
 /// first [resetting][Reset] the value of the attribute,
 /// and then applying each modifier attached to the same entity,
 /// sorted by their [priority][Modifier::PRIORITY].
+/// Modifiers in the same [group][ModifierGroup] are combined and applied together.
 ///
 /// All attributes should be registered by adding [`AttributePlugin`]s to your app.
 ///
@@ -116,10 +133,10 @@ impl<T: Default> Reset for T {
 ///
 /// app.update();
 /// app.update();
-/// // during this update:
-/// // 1. In `CoreStage::Update`, the `ModifierPlugin` notices that the `ExtraMaxHealth` modifier was added
+/// // during these updates:
+/// // 1. In the `AttributeStage`, the `ModifierPlugin` notices that the `ExtraMaxHealth` modifier was added
 /// //    to an entity with the `MaxHealth` attribute and gives the entity the `DirtyAttr<MaxHealth>` component.
-/// // 2. In `CoreStage::PostUpdate`, the `AttributePlugin` notices that the `DirtyAttr` component was added
+/// // 2. In the next `AttributeStage`, the `AttributePlugin` notices that the `DirtyAttr` component was added
 /// //    and recalculates the attribute. First it resets the attribute value to `MaxHealth(100)`,
 /// //    and then it adds the health from the `ExtraMaxHealth` modifier (a total of 150).
 /// //    The `DirtyAttr` component is then removed.
@@ -140,7 +157,61 @@ impl<T: Default> Reset for T {
 ///     assert_eq!(**max_health, 100);
 /// }
 /// ```
-pub trait Attribute: Component + Reset {}
+pub trait Attribute: Component + Reset {
+    /// Returns a [`Snapshot`] of the attribute's current value,
+    /// which decides whether the value is kept after the attribute is recomputed.
+    ///
+    /// If the recomputed value is not [significant][Snapshot::new],
+    /// the previous value is restored and the attribute is not marked as changed,
+    /// so nothing which depends on the attribute is recomputed either.
+    /// This is useful for float attributes whose modifiers change slightly every frame.
+    ///
+    /// The default implementation returns `None`, meaning every recomputed value is kept.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_attr::{Attribute, Snapshot};
+    ///
+    /// #[derive(Component, Deref, DerefMut, Clone, Default)]
+    /// struct Speed(f32);
+    ///
+    /// impl Attribute for Speed {
+    ///     fn snapshot(&self) -> Option<Snapshot<Self>> {
+    ///         Some(Snapshot::new(self.clone(), |speed, previous| {
+    ///             (**speed - **previous).abs() > 0.01
+    ///         }))
+    ///     }
+    /// }
+    /// ```
+    fn snapshot(&self) -> Option<Snapshot<Self>>
+    where
+        Self: Sized,
+    {
+        None
+    }
+}
+
+/// A copy of an attribute's value from before it was recomputed.
+///
+/// See [`Attribute::snapshot`] for more info.
+pub struct Snapshot<A> {
+    previous: A,
+    recompute_is_significant: fn(&A, &A) -> bool,
+}
+
+impl<A> Snapshot<A> {
+    /// Creates a snapshot of the `previous` value of an attribute.
+    ///
+    /// Once the attribute is recomputed, `recompute_is_significant` is called with the new and previous values,
+    /// and returns whether the new value is different enough from the previous one to be kept.
+    pub fn new(previous: A, recompute_is_significant: fn(&A, &A) -> bool) -> Self {
+        Self {
+            previous,
+            recompute_is_significant,
+        }
+    }
+}
 
 /// Indicates the priority of a modifier.
 ///
@@ -193,10 +264,78 @@ pub trait Attribute: Component + Reset {}
 /// }
 /// ```
 ///
+/// # Anchors
+///
+/// Modifiers from separate crates can't always name each other's priorities.
+/// Instead, a priority can be registered under a name with [`add_modifier_anchor`]
+/// and referred to anywhere with [`anchor`].
+/// Anchors are resolved when the app starts, so they may be registered in any order,
+/// but must all be registered before the app's first update.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_attr::{Attribute, AttributePlugin, Modifier, ModifierAnchorExt, ModifierPlugin, ModifierPriority};
+///
+/// #[derive(Component, Deref, DerefMut, Default)]
+/// struct Health(usize);
+///
+/// impl Attribute for Health {}
+///
+/// // in one crate:
+/// #[derive(Component)]
+/// struct Armor;
+///
+/// impl Modifier for Armor {
+///     type Attr = Health;
+///
+///     const PRIORITY: ModifierPriority<Health> = ModifierPriority::anchor("armor");
+///
+///     fn apply(&self, health: &mut Health) {
+///         **health *= 2;
+///     }
+/// }
+///
+/// // in another crate:
+/// #[derive(Component)]
+/// struct Blessing;
+///
+/// impl Modifier for Blessing {
+///     type Attr = Health;
+///
+///     // always added before the health is doubled by armor.
+///     const PRIORITY: ModifierPriority<Health> = ModifierPriority::anchor("armor").before();
+///
+///     fn apply(&self, health: &mut Health) {
+///         **health += 10;
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(MinimalPlugins);
+///
+/// app.add_plugin(AttributePlugin::<Health>::default())
+///     .add_plugin(ModifierPlugin::<Armor>::default())
+///     .add_plugin(ModifierPlugin::<Blessing>::default());
+///
+/// // both crates may register the same anchor, as long as they agree on its priority.
+/// app.add_modifier_anchor("armor", ModifierPriority::<Health>::ZERO.after());
+/// app.add_modifier_anchor("armor", ModifierPriority::<Health>::ZERO.after());
+///
+/// let id = app.world.spawn((Health::default(), Armor, Blessing)).id();
+///
+/// app.update();
+/// app.update();
+///
+/// assert_eq!(**app.world.get::<Health>(id).unwrap(), 20);
+/// ```
+///
 /// [`ZERO`]: [`ModifierPriority::ZERO`]
 /// [`after`]: [`ModifierPriority::after`]
 /// [`before`]: [`ModifierPriority::before`]
+/// [`anchor`]: ModifierPriority::anchor
+/// [`add_modifier_anchor`]: ModifierAnchorExt::add_modifier_anchor
 pub struct ModifierPriority<A: Attribute> {
+    anchor: Option<&'static str>,
     index: isize,
     _marker: PhantomData<A>,
 }
@@ -204,55 +343,380 @@ pub struct ModifierPriority<A: Attribute> {
 impl<A: Attribute> fmt::Debug for ModifierPriority<A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ModifierPriority")
+            .field("anchor", &self.anchor)
             .field("index", &self.index)
             .finish()
     }
 }
 
 impl<A: Attribute> ModifierPriority<A> {
-    pub(self) const fn new(index: isize) -> Self {
+    pub(self) const fn new(anchor: Option<&'static str>, index: isize) -> Self {
         Self {
+            anchor,
             index,
             _marker: PhantomData,
         }
     }
 
     /// The default priority.
-    pub const ZERO: Self = Self::new(0);
-    
+    pub const ZERO: Self = Self::new(None, 0);
+
+    /// Returns the priority registered under `name` with [`add_modifier_anchor`].
+    ///
+    /// The app will panic when it starts if a [`Modifier`] refers to an anchor which was never registered.
+    /// [`ModifierGeneric`] priorities are only known once there is a modifier,
+    /// so an unregistered anchor there panics when the attribute is refreshed instead.
+    ///
+    /// [`add_modifier_anchor`]: ModifierAnchorExt::add_modifier_anchor
+    pub const fn anchor(name: &'static str) -> Self {
+        Self::new(Some(name), 0)
+    }
+
     /// Returns a new priority immediately after `self`.
     pub const fn after(self) -> Self {
-        Self::new(self.index + 1)
+        Self::new(self.anchor, self.index + 1)
     }
 
     /// Returns a new priority immediately before `self`
     pub const fn before(self) -> Self {
-        Self::new(self.index - 1)
+        Self::new(self.anchor, self.index - 1)
     }
 }
 
+/// Priorities are compared before their anchors are resolved,
+/// so a priority relative to an anchor is never equal to one which isn't,
+/// even if they end up in the same place.
 impl<A: Attribute> PartialEq for ModifierPriority<A> {
     fn eq(&self, other: &Self) -> bool {
-        self.index == other.index
+        self.anchor == other.anchor && self.index == other.index
     }
 }
 
 impl<A: Attribute> Eq for ModifierPriority<A> {}
 
+/// Only priorities relative to the same anchor (or to no anchor) can be ordered.
 impl<A: Attribute> PartialOrd for ModifierPriority<A> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+        (self.anchor == other.anchor).then(|| self.index.cmp(&other.index))
+    }
+}
+
+/// The anchors registered for the modifiers of an [`Attribute`].
+#[derive(Resource)]
+struct ModifierAnchors<A: Attribute> {
+    /// Every registration of every anchor, in the order they were registered.
+    registered: Vec<(&'static str, ModifierPriority<A>)>,
+    /// The priorities of registered modifiers, by type name, which are checked when the app starts.
+    modifiers: Vec<(&'static str, ModifierPriority<A>)>,
+    resolved: HashMap<&'static str, isize>,
+}
+
+impl<A: Attribute> Default for ModifierAnchors<A> {
+    fn default() -> Self {
+        Self {
+            registered: Vec::new(),
+            modifiers: Vec::new(),
+            resolved: HashMap::default(),
+        }
+    }
+}
+
+enum AnchorError {
+    Missing(&'static str),
+    Cyclic(&'static str),
+    Conflict(&'static str, isize, isize),
+}
+
+impl fmt::Display for AnchorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnchorError::Missing(name) => write!(f, "modifier anchor `{name}` was never registered"),
+            AnchorError::Cyclic(name) => write!(f, "modifier anchor `{name}` is defined in terms of itself"),
+            AnchorError::Conflict(name, a, b) => write!(
+                f,
+                "modifier anchor `{name}` was registered with incompatible priorities (resolving to {a} and {b})"
+            ),
+        }
+    }
+}
+
+impl<A: Attribute> ModifierAnchors<A> {
+    fn resolve_anchor(
+        &self,
+        name: &'static str,
+        visiting: &mut Vec<&'static str>,
+    ) -> Result<isize, AnchorError> {
+        if visiting.contains(&name) {
+            return Err(AnchorError::Cyclic(name));
+        }
+        visiting.push(name);
+        let mut index = None;
+        // different registrations are fine as long as they end up in the same place.
+        for (_, priority) in self.registered.iter().filter(|(other, _)| *other == name) {
+            let resolved = self.resolve(priority, visiting)?;
+            match index {
+                Some(index) if index != resolved => return Err(AnchorError::Conflict(name, index, resolved)),
+                _ => index = Some(resolved),
+            }
+        }
+        visiting.pop();
+        index.ok_or(AnchorError::Missing(name))
+    }
+
+    fn resolve(
+        &self,
+        priority: &ModifierPriority<A>,
+        visiting: &mut Vec<&'static str>,
+    ) -> Result<isize, AnchorError> {
+        match priority.anchor {
+            Some(name) => Ok(self.resolve_anchor(name, visiting)? + priority.index),
+            None => Ok(priority.index),
+        }
+    }
+
+    /// Resolves every registered anchor, and checks the anchors of every registered modifier exist.
+    fn resolve_all(&mut self) -> Result<(), String> {
+        let mut names: Vec<_> = self.registered.iter().map(|(name, _)| *name).collect();
+        names.sort_unstable();
+        names.dedup();
+
+        let mut resolved = HashMap::default();
+        for name in names {
+            let index = self.resolve_anchor(name, &mut Vec::new()).map_err(|error| error.to_string())?;
+            resolved.insert(name, index);
+        }
+        for (modifier, priority) in &self.modifiers {
+            if let Some(name) = priority.anchor.filter(|name| !resolved.contains_key(name)) {
+                return Err(format!("{} (used by modifier {modifier})", AnchorError::Missing(name)));
+            }
+        }
+        self.resolved = resolved;
+        Ok(())
+    }
+
+    fn index_of(&self, priority: &ModifierPriority<A>) -> isize {
+        let Some(name) = priority.anchor else {
+            return priority.index;
+        };
+        match self.resolved.get(name) {
+            Some(index) => index + priority.index,
+            None => panic!("{} (for attribute {})", AnchorError::Missing(name), std::any::type_name::<A>()),
+        }
+    }
+}
+
+fn resolve_modifier_anchors<A: Attribute>(mut anchors: ResMut<ModifierAnchors<A>>) {
+    if let Err(error) = anchors.resolve_all() {
+        panic!("{error} (for attribute {})", std::any::type_name::<A>());
+    }
+}
+
+/// Extension trait for registering named modifier priorities on an [`App`].
+///
+/// See [`ModifierPriority`] for more info on anchors.
+pub trait ModifierAnchorExt {
+    /// Registers `priority` under `name`, so it can be referred to with [`ModifierPriority::anchor`].
+    ///
+    /// The same anchor may be registered several times, as long as each registration resolves to the same priority.
+    ///
+    /// # Panics
+    /// The app will panic when it starts if the registrations of `name` resolve to different priorities,
+    /// or if an anchor is missing or defined in terms of itself.
+    fn add_modifier_anchor<A: Attribute>(
+        &mut self,
+        name: &'static str,
+        priority: ModifierPriority<A>,
+    ) -> &mut Self;
+}
+
+impl ModifierAnchorExt for App {
+    fn add_modifier_anchor<A: Attribute>(
+        &mut self,
+        name: &'static str,
+        priority: ModifierPriority<A>,
+    ) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(ModifierAnchors::<A>::default)
+            .registered
+            .push((name, priority));
+        self
+    }
+}
+
+/// A group of modifiers which are combined before being applied to their attribute.
+///
+/// Modifiers join a group through [`Modifier::GROUP`] (or [`ModifierGeneric::group`]).
+/// When an attribute is refreshed, all of its modifiers in the same group are passed to [`combine`] at once,
+/// at the position of the earliest (by [priority][Modifier::PRIORITY]) modifier in the group.
+///
+/// # Examples
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_attr::{
+///     Attribute, AttributePlugin, Modifier, ModifierCtx, ModifierGeneric, ModifierGroup,
+///     ModifierGroupId, ModifierPlugin, ModifierPriority,
+/// };
+///
+/// #[derive(Component, Deref, DerefMut)]
+/// struct Damage(f32);
+///
+/// impl Default for Damage {
+///     fn default() -> Self {
+///         Self(10.)
+///     }
+/// }
+///
+/// impl Attribute for Damage {}
+///
+/// // fire resistances are summed and capped at 75% before reducing the damage once.
+/// struct FireResistances;
+///
+/// impl ModifierGroup<Damage> for FireResistances {
+///     fn combine(
+///         modifiers: &[&dyn ModifierGeneric<Damage>],
+///         damage: &mut Damage,
+///         ctx: &ModifierCtx<'_, Damage>,
+///     ) {
+///         let mut resistance = Damage(0.);
+///         for modifier in modifiers {
+///             modifier.apply_with(&mut resistance, ctx);
+///         }
+///         **damage *= 1. - resistance.min(0.75);
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct FireCloak;
+///
+/// impl Modifier for FireCloak {
+///     type Attr = Damage;
+///
+///     const PRIORITY: ModifierPriority<Damage> = ModifierPriority::ZERO;
+///     const GROUP: Option<ModifierGroupId<Damage>> = Some(ModifierGroupId::of::<FireResistances>());
+///
+///     fn apply(&self, resistance: &mut Damage) {
+///         **resistance += 0.5;
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct FireRing;
+///
+/// impl Modifier for FireRing {
+///     type Attr = Damage;
+///
+///     const PRIORITY: ModifierPriority<Damage> = ModifierPriority::ZERO;
+///     const GROUP: Option<ModifierGroupId<Damage>> = Some(ModifierGroupId::of::<FireResistances>());
+///
+///     fn apply(&self, resistance: &mut Damage) {
+///         **resistance += 0.5;
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(MinimalPlugins);
+///
+/// app.add_plugin(AttributePlugin::<Damage>::default())
+///     .add_plugin(ModifierPlugin::<FireCloak>::default())
+///     .add_plugin(ModifierPlugin::<FireRing>::default());
+///
+/// let id = app.world.spawn((Damage::default(), FireCloak, FireRing)).id();
+///
+/// app.update();
+/// app.update();
+///
+/// assert_eq!(**app.world.get::<Damage>(id).unwrap(), 2.5);
+/// ```
+///
+/// [`combine`]: ModifierGroup::combine
+pub trait ModifierGroup<A: Attribute>: Send + Sync + 'static {
+    /// Combines every modifier in the group and applies the result to `attr`.
+    ///
+    /// `modifiers` is sorted by priority.
+    fn combine(modifiers: &[&dyn ModifierGeneric<A>], attr: &mut A, ctx: &ModifierCtx<'_, A>);
+}
+
+/// Identifies the [`ModifierGroup`] a modifier belongs to.
+///
+/// Created with [`ModifierGroupId::of`].
+pub struct ModifierGroupId<A: Attribute> {
+    type_id: fn() -> TypeId,
+    combine: fn(&[&dyn ModifierGeneric<A>], &mut A, &ModifierCtx<'_, A>),
+}
+
+impl<A: Attribute> ModifierGroupId<A> {
+    /// Returns the id of the group `G`.
+    pub const fn of<G: ModifierGroup<A>>() -> Self {
+        Self {
+            type_id: TypeId::of::<G>,
+            combine: G::combine,
+        }
+    }
+}
+
+impl<A: Attribute> Clone for ModifierGroupId<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Attribute> Copy for ModifierGroupId<A> {}
+
+impl<A: Attribute> PartialEq for ModifierGroupId<A> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.type_id)() == (other.type_id)()
+    }
+}
+
+impl<A: Attribute> Eq for ModifierGroupId<A> {}
+
+impl<A: Attribute> fmt::Debug for ModifierGroupId<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModifierGroupId")
+            .field("type_id", &(self.type_id)())
+            .finish()
+    }
+}
+
+/// Read-only access to other attributes on the same entity as an attribute being refreshed.
+///
+/// Passed to [`Modifier::apply_with`].
+/// Only attributes declared with [`AttributePlugin::reads`] are available.
+pub struct ModifierCtx<'a, A: Attribute> {
+    reads: Option<ReadTraits<'a, dyn AttributeRead<A>>>,
+}
+
+impl<'a, A: Attribute> ModifierCtx<'a, A> {
+    /// Returns the attribute `B` on the same entity.
+    ///
+    /// Returns `None` if the entity has no `B`,
+    /// or if `B` was not declared with [`AttributePlugin::reads`] for `A`.
+    pub fn get<B: Attribute>(&self) -> Option<&'a B> {
+        self.reads
+            .iter()
+            .flat_map(|reads| reads.iter())
+            .find_map(|read| read.as_any().downcast_ref())
     }
 }
 
-impl<A: Attribute> Ord for ModifierPriority<A> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.index.cmp(&other.index)
+/// Type-erased access to the components registered with [`AttributePlugin::reads`].
+#[bevy_trait_query::queryable]
+trait AttributeRead<A: Attribute>: Send + Sync + 'static {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<C: Component, A: Attribute> AttributeRead<A> for C {
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 }
 
 /// A generic version of [`Modifier`].
-/// 
+///
+/// Unlike [`Modifier`], a single type can implement `ModifierGeneric` for several attributes,
+/// including a whole family of generic attributes at once.
+/// See [`ModifierGenericPlugin`] for an example.
+///
 /// See [`Modifier`] for more info.
 #[bevy_trait_query::queryable]
 pub trait ModifierGeneric<A: Attribute>: Send + Sync + 'static {
@@ -261,13 +725,36 @@ pub trait ModifierGeneric<A: Attribute>: Send + Sync + 'static {
     /// See [`Modifier::PRIORITY`] for more info.
     fn priority(&self) -> ModifierPriority<A>;
 
+    /// Returns the priority shared by every instance of this modifier, if there is one.
+    ///
+    /// This lets the anchor of the priority be checked when the app starts, rather than once there is a modifier.
+    fn shared_priority() -> Option<ModifierPriority<A>>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
     /// Returns whether this modifier is dependent on an exact order.
     ///
     /// See [`Modifier::IS_ORDER_INDEPENDENT`] for more info.
     fn is_order_indepedent(&self) -> bool { false }
 
+    /// Returns the group this modifier belongs to, if any.
+    ///
+    /// See [`Modifier::GROUP`] for more info.
+    fn group(&self) -> Option<ModifierGroupId<A>> { None }
+
     /// Applies the modifier to an instance of its associated attribute.
     fn apply(&self, attr: &mut A);
+
+    /// Applies the modifier, with access to other attributes on the same entity.
+    ///
+    /// See [`Modifier::apply_with`] for more info.
+    fn apply_with(&self, attr: &mut A, ctx: &ModifierCtx<'_, A>) {
+        let _ = ctx;
+        self.apply(attr);
+    }
 }
 
 /// A modifier on an [`Attribute`].
@@ -299,8 +786,102 @@ pub trait Modifier: Send + Sync + 'static {
     /// [`PRIORITY`]: [`Modifier::PRIORITY`].
     const IS_ORDER_INDEPENDENT: bool = false;
 
+    /// The [`ModifierGroup`] this modifier belongs to.
+    ///
+    /// Modifiers in the same group are [combined][ModifierGroup::combine]
+    /// rather than each being applied in turn.
+    ///
+    /// The default value is `None`, meaning the modifier is applied on its own.
+    const GROUP: Option<ModifierGroupId<Self::Attr>> = None;
+
     /// Applies the modifier to an instance of its associated attribute.
     fn apply(&self, attr: &mut Self::Attr);
+
+    /// Applies the modifier to an instance of its associated attribute,
+    /// with read-only access to other attributes on the same entity.
+    ///
+    /// Attributes are only available through `ctx` if they are declared with [`AttributePlugin::reads`],
+    /// which also makes sure they are refreshed before this modifier is applied.
+    ///
+    /// This is what is called when attributes are refreshed.
+    /// The default implementation ignores `ctx` and calls [`apply`][Modifier::apply].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_attr::{AttributePlugin, ModifierPlugin, Attribute, Modifier, ModifierCtx, ModifierPriority};
+    ///
+    /// #[derive(Component, Deref, DerefMut)]
+    /// struct Health(usize);
+    ///
+    /// impl Default for Health {
+    ///     fn default() -> Self {
+    ///         Self(100)
+    ///     }
+    /// }
+    ///
+    /// impl Attribute for Health {}
+    ///
+    /// #[derive(Component, Deref, DerefMut, Default)]
+    /// struct Armor(usize);
+    ///
+    /// impl Attribute for Armor {}
+    ///
+    /// #[derive(Component)]
+    /// struct Plating;
+    ///
+    /// impl Modifier for Plating {
+    ///     type Attr = Armor;
+    ///
+    ///     const PRIORITY: ModifierPriority<Armor> = ModifierPriority::ZERO;
+    ///
+    ///     fn apply(&self, armor: &mut Armor) {
+    ///         **armor += 5;
+    ///     }
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Damage(usize);
+    ///
+    /// impl Modifier for Damage {
+    ///     type Attr = Health;
+    ///
+    ///     const PRIORITY: ModifierPriority<Health> = ModifierPriority::ZERO;
+    ///
+    ///     fn apply(&self, health: &mut Health) {
+    ///         **health = health.saturating_sub(self.0);
+    ///     }
+    ///
+    ///     // armor reduces the damage taken.
+    ///     fn apply_with(&self, health: &mut Health, ctx: &ModifierCtx<'_, Health>) {
+    ///         let armor = ctx.get::<Armor>().map_or(0, |armor| **armor);
+    ///         **health = health.saturating_sub(self.0.saturating_sub(armor));
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(MinimalPlugins);
+    ///
+    /// app.add_plugin(AttributePlugin::<Armor>::default())
+    ///     .add_plugin(AttributePlugin::<Health>::default().reads::<Armor>())
+    ///     .add_plugin(ModifierPlugin::<Plating>::default())
+    ///     .add_plugin(ModifierPlugin::<Damage>::default());
+    ///
+    /// let id = app
+    ///     .world
+    ///     .spawn((Health::default(), Armor::default(), Plating, Damage(20)))
+    ///     .id();
+    ///
+    /// app.update();
+    /// app.update();
+    ///
+    /// // `Armor` was refreshed before `Health` in the same update.
+    /// assert_eq!(**app.world.get::<Health>(id).unwrap(), 85);
+    /// ```
+    fn apply_with(&self, attr: &mut Self::Attr, ctx: &ModifierCtx<'_, Self::Attr>) {
+        let _ = ctx;
+        Modifier::apply(self, attr);
+    }
 }
 
 impl<M: Modifier> ModifierGeneric<M::Attr> for M {
@@ -308,13 +889,25 @@ impl<M: Modifier> ModifierGeneric<M::Attr> for M {
         M::PRIORITY
     }
 
+    fn shared_priority() -> Option<ModifierPriority<M::Attr>> {
+        Some(M::PRIORITY)
+    }
+
     fn is_order_indepedent(&self) -> bool {
         M::IS_ORDER_INDEPENDENT
     }
 
+    fn group(&self) -> Option<ModifierGroupId<M::Attr>> {
+        M::GROUP
+    }
+
     fn apply(&self, attr: &mut M::Attr) {
         <M as Modifier>::apply(self, attr)
     }
+
+    fn apply_with(&self, attr: &mut M::Attr, ctx: &ModifierCtx<'_, M::Attr>) {
+        <M as Modifier>::apply_with(self, attr, ctx)
+    }
 }
 
 trait ModifierExt<A: Attribute>: ModifierGeneric<A> {
@@ -328,66 +921,561 @@ impl<M: ModifierGeneric<A> + ?Sized + 'static, A: Attribute> ModifierExt<A> for
 /// Registers the required information for an [`Attribute`].
 ///
 /// The relevant [`ModifierPlugin`]s should also be added to your app.
-#[derive(Default)]
-pub struct AttributePlugin<A: Attribute>(PhantomData<A>);
-
-fn refresh_dirty_attr<A: Attribute>(
-    mut attrs: Query<(Entity, &mut A, Option<&dyn ModifierGeneric<A>>), With<DirtyAttr<A>>>,
-    mut commands: Commands,
-) {
-    for (dirty, mut attr, mods) in attrs.iter_mut() {
-        debug!("some modifiers have changed!");
-        let mut mods: Vec<_> = mods.map_or_else(Vec::new, |mods| mods.iter().collect());
-        mods.sort_unstable_by(|a, b| {
-            let order = a.priority().cmp(&b.priority());
-            #[cfg(debug_assertions)]
-            if let Ordering::Equal = order {
-                if a.is_order_indepedent() || b.is_order_indepedent() {
-                    warn!(
-                        "ambiguity between the order of two modifiers ({} and {} have the same priority)",
-                        a.type_name(),
-                        b.type_name(),
-                    );
-                }
-            }
-            order
-        });
-
-        Reset::reset(&mut *attr);
-
-        for modifier in mods.iter() {
-            modifier.apply(&mut attr);
-        }
-
-        commands.get_entity(dirty).unwrap().remove::<DirtyAttr<A>>();
-    }
-}
-
-impl<A: Attribute> Plugin for AttributePlugin<A> {
-    fn build(&self, app: &mut App) {
-        app.add_system_to_stage(CoreStage::PostUpdate, refresh_dirty_attr::<A>);
-    }
-}
-
-/// Registers the required information for a [`ModifierGeneric`].
 ///
-/// The relevant [`AttributePlugin`] should also be added to your app.
-pub struct ModifierGenericPlugin<M: ModifierGeneric<A>, A: Attribute>(PhantomData<(M, A)>);
-
-impl<M: ModifierGeneric<A>, A: Attribute> Default for ModifierGenericPlugin<M, A> {
-    fn default() -> Self {
-        Self(PhantomData)
-    }
-}
-
-/// Marker component to indicates that an [`Attribute`]'s modifiers have changed since the last update.
-#[derive(Component)]
-pub struct DirtyAttr<A: Attribute>(PhantomData<A>);
-
-impl<A: Attribute> Default for DirtyAttr<A> {
-    fn default() -> Self {
-        Self(PhantomData)
-    }
+/// By default, attributes are refreshed in a single pass of the [`AttributeStage`] each frame,
+/// so an attribute which depends on another attribute (through a modifier) is only refreshed the following frame.
+/// Use [`until_stable`] to instead keep refreshing within the same frame until the attribute stops changing.
+///
+/// # Examples
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_attr::{AttributePlugin, ModifierPlugin, Attribute, Modifier, ModifierPriority};
+///
+/// #[derive(Component, Deref, DerefMut, Default)]
+/// struct MaxHealth(usize);
+///
+/// impl Attribute for MaxHealth {}
+///
+/// #[derive(Component)]
+/// struct ExtraMaxHealth;
+///
+/// impl Modifier for ExtraMaxHealth {
+///     type Attr = MaxHealth;
+///
+///     const PRIORITY: ModifierPriority<MaxHealth> = ModifierPriority::ZERO;
+///
+///     fn apply(&self, value: &mut MaxHealth) {
+///         **value += 50;
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(MinimalPlugins);
+///
+/// app.add_plugin(AttributePlugin::<MaxHealth>::default().until_stable(16));
+/// app.add_plugin(ModifierPlugin::<ExtraMaxHealth>::default());
+///
+/// let id = app.world.spawn((MaxHealth::default(), ExtraMaxHealth)).id();
+///
+/// // a single update is enough.
+/// app.update();
+///
+/// assert_eq!(**app.world.get::<MaxHealth>(id).unwrap(), 50);
+/// ```
+///
+/// [`until_stable`]: AttributePlugin::until_stable
+pub struct AttributePlugin<A: Attribute> {
+    max_iterations: Option<usize>,
+    reads: Vec<AttributeReadInfo>,
+    _marker: PhantomData<A>,
+}
+
+impl<A: Attribute> Default for AttributePlugin<A> {
+    fn default() -> Self {
+        Self {
+            max_iterations: None,
+            reads: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+struct AttributeReadInfo {
+    type_id: TypeId,
+    type_name: &'static str,
+    register: fn(&mut App),
+    refresh_after: fn(SystemDescriptor) -> SystemDescriptor,
+}
+
+impl<A: Attribute> AttributePlugin<A> {
+    /// Keeps refreshing `A` within a single frame until no [`DirtyAttr<A>`] remains,
+    /// refreshing it at most `max_iterations` times each frame.
+    ///
+    /// Each iteration refreshes `A` once if any of its modifiers changed,
+    /// so a single changed modifier needs one iteration, and a chain of `n` dependent attributes needs `n`.
+    /// Attributes without `until_stable` are unaffected, and are still refreshed once each frame.
+    ///
+    /// If `A` is still dirty after `max_iterations`, an error naming it is logged.
+    /// This usually indicates a cyclic dependency between attributes.
+    ///
+    /// # Panics
+    /// Panics if `max_iterations` is `0`.
+    pub fn until_stable(mut self, max_iterations: usize) -> Self {
+        assert_ne!(max_iterations, 0, "attributes must be refreshed at least once");
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Makes the attribute `B` on the same entity available to modifiers through [`ModifierCtx`].
+    ///
+    /// `B` is always refreshed before `A`, and `A` is marked dirty whenever `B` changes.
+    /// `A` is then refreshed the next frame, or in the same frame if [`until_stable`] is used.
+    ///
+    /// See [`Modifier::apply_with`] for an example.
+    ///
+    /// # Panics
+    /// Panics if `B` is `A`.
+    /// Adding the plugin panics if `B` also reads `A`, directly or through other attributes.
+    ///
+    /// [`until_stable`]: AttributePlugin::until_stable
+    pub fn reads<B: Attribute>(mut self) -> Self {
+        assert_ne!(
+            TypeId::of::<A>(),
+            TypeId::of::<B>(),
+            "attribute {} can't read itself",
+            std::any::type_name::<A>(),
+        );
+        self.reads.push(AttributeReadInfo {
+            type_id: TypeId::of::<B>(),
+            type_name: std::any::type_name::<B>(),
+            register: register_read::<A, B>,
+            refresh_after: refresh_after::<B>,
+        });
+        self
+    }
+}
+
+fn register_read<A: Attribute, B: Attribute>(app: &mut App) {
+    app.register_component_as::<dyn AttributeRead<A>, B>();
+    let systems = || {
+        SystemSet::new()
+            .with_system(read_changed::<B, A>)
+            .with_system(read_removed::<B, A>)
+    };
+    attribute_refresh(app)
+        .add_detect_systems::<A>(systems().after(refresh_dirty_attr::<B>), systems());
+}
+
+fn refresh_after<B: Attribute>(refresh: SystemDescriptor) -> SystemDescriptor {
+    refresh.after(refresh_dirty_attr::<B>)
+}
+
+/// The stage in which attributes are refreshed.
+///
+/// Runs immediately after [`CoreStage::PostUpdate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, StageLabel)]
+pub struct AttributeStage;
+
+/// Holds the systems which refresh attributes, so they can be run several times a frame.
+///
+/// Every system is in `stage`, for the usual pass, and also in either `detect` or `refresh`,
+/// which alternate for attributes refreshed [until stable][AttributePlugin::until_stable].
+/// Run criteria decide which copy of each system actually runs.
+#[derive(Resource)]
+struct AttributeRefresh {
+    stage: SystemStage,
+    detect: SystemStage,
+    refresh: SystemStage,
+    attributes: Vec<DirtyCheck>,
+    stable_checks: Vec<(DirtyCheck, usize)>,
+    /// The attributes declared with [`AttributePlugin::reads`], by the attribute reading them.
+    reads: HashMap<TypeId, Vec<(TypeId, &'static str)>>,
+}
+
+impl Default for AttributeRefresh {
+    fn default() -> Self {
+        Self {
+            stage: SystemStage::parallel(),
+            detect: SystemStage::parallel(),
+            refresh: SystemStage::parallel(),
+            attributes: Vec::new(),
+            stable_checks: Vec::new(),
+            reads: HashMap::default(),
+        }
+    }
+}
+
+/// The current iteration of the [`AttributeStage`] this frame.
+///
+/// Iteration `0` is the usual pass, which only refreshes attributes without a maximum number of iterations.
+#[derive(Resource, Default)]
+struct RefreshIteration {
+    iteration: usize,
+    /// The maximum iterations of the attributes refreshed [until stable][AttributePlugin::until_stable].
+    max_iterations: HashMap<TypeId, usize>,
+}
+
+impl RefreshIteration {
+    fn max_iterations<A: Attribute>(&self) -> Option<usize> {
+        self.max_iterations.get(&TypeId::of::<A>()).copied()
+    }
+}
+
+fn should_run(run: bool) -> ShouldRun {
+    if run {
+        ShouldRun::Yes
+    } else {
+        ShouldRun::No
+    }
+}
+
+fn refresh_once<A: Attribute>(iteration: Res<RefreshIteration>) -> ShouldRun {
+    should_run(iteration.max_iterations::<A>().is_none())
+}
+
+fn detect_until_stable<A: Attribute>(iteration: Res<RefreshIteration>) -> ShouldRun {
+    // one more iteration than refreshing, to notice if the last refresh wasn't stable.
+    should_run(iteration.max_iterations::<A>().is_some_and(|max| iteration.iteration <= max + 1))
+}
+
+fn refresh_until_stable<A: Attribute>(iteration: Res<RefreshIteration>) -> ShouldRun {
+    should_run(iteration.max_iterations::<A>().is_some_and(|max| iteration.iteration <= max))
+}
+
+#[derive(Clone, Copy)]
+struct DirtyCheck {
+    is_dirty: fn(&mut World) -> bool,
+    type_name: &'static str,
+}
+
+fn is_dirty<A: Attribute>(world: &mut World) -> bool {
+    world
+        .query_filtered::<(), With<DirtyAttr<A>>>()
+        .iter(world)
+        .next()
+        .is_some()
+}
+
+fn attribute_refresh(app: &mut App) -> Mut<'_, AttributeRefresh> {
+    if !app.world.contains_resource::<AttributeRefresh>() {
+        app.add_stage_after(
+            CoreStage::PostUpdate,
+            AttributeStage,
+            SystemStage::single_threaded().with_system(refresh_attributes),
+        );
+        app.init_resource::<AttributeRefresh>();
+        app.init_resource::<RefreshIteration>();
+    }
+    app.world.resource_mut::<AttributeRefresh>()
+}
+
+impl AttributeRefresh {
+    /// Returns the names of the attributes in a cycle of reads from `A` back to itself, if there is one.
+    fn read_cycle<A: Attribute>(&self) -> Option<Vec<&'static str>> {
+        let attr = TypeId::of::<A>();
+        let mut visited = HashSet::default();
+        let mut stack = vec![(attr, vec![std::any::type_name::<A>()])];
+        while let Some((reader, path)) = stack.pop() {
+            for &(read, name) in self.reads.get(&reader).into_iter().flatten() {
+                let mut path = path.clone();
+                path.push(name);
+                if read == attr {
+                    return Some(path);
+                }
+                if visited.insert(read) {
+                    stack.push((read, path));
+                }
+            }
+        }
+        None
+    }
+
+    /// Adds systems which mark `A` as dirty,
+    /// `once` for the usual pass and `until_stable` for the iterations after it.
+    fn add_detect_systems<A: Attribute>(&mut self, once: SystemSet, until_stable: SystemSet) {
+        self.stage.add_system_set(
+            once.with_run_criteria(refresh_once::<A>)
+                .before(refresh_dirty_attr::<A>),
+        );
+        self.detect
+            .add_system_set(until_stable.with_run_criteria(detect_until_stable::<A>));
+    }
+
+    /// Runs the usual pass, then refreshes the attributes which should be stable until they have settled.
+    ///
+    /// Returns the attributes which were still dirty after their maximum number of iterations.
+    fn run(&mut self, world: &mut World) -> Result<(), Vec<&'static str>> {
+        world.resource_mut::<RefreshIteration>().iteration = 0;
+        self.stage.run(world);
+        if self.stable_checks.is_empty() {
+            return Ok(());
+        }
+
+        // `DirtyAttr` is inserted with commands, so attributes are marked dirty and refreshed in separate stages.
+        let mut exhausted = Vec::new();
+        for iteration in 1.. {
+            world.resource_mut::<RefreshIteration>().iteration = iteration;
+            self.detect.run(world);
+
+            let mut refreshing = false;
+            for &(check, max_iterations) in &self.stable_checks {
+                if !(check.is_dirty)(world) {
+                    continue;
+                }
+                if iteration <= max_iterations {
+                    refreshing = true;
+                } else if iteration == max_iterations + 1 {
+                    exhausted.push(check.type_name);
+                }
+            }
+            if !refreshing {
+                break;
+            }
+            self.refresh.run(world);
+        }
+
+        if exhausted.is_empty() {
+            Ok(())
+        } else {
+            Err(exhausted)
+        }
+    }
+}
+
+fn refresh_attributes(world: &mut World) {
+    world.resource_scope(|world, mut refresh: Mut<AttributeRefresh>| {
+        if let Err(dirty) = refresh.run(world) {
+            error!(
+                "attributes did not stabilize within their maximum iterations, is there a cyclic dependency? (still dirty: {})",
+                dirty.join(", "),
+            );
+        }
+    });
+}
+
+#[allow(clippy::type_complexity)]
+fn refresh_dirty_attr<A: Attribute>(
+    mut attrs: Query<(Entity, &mut A, Option<&dyn ModifierGeneric<A>>), With<DirtyAttr<A>>>,
+    anchors: Res<ModifierAnchors<A>>,
+    mut commands: Commands,
+) {
+    for (dirty, attr, mods) in attrs.iter_mut() {
+        refresh_attr(attr, mods, ModifierCtx { reads: None }, &anchors);
+        commands.get_entity(dirty).unwrap().remove::<DirtyAttr<A>>();
+    }
+}
+
+/// Replaces [`refresh_dirty_attr`] for attributes with [`AttributePlugin::reads`],
+/// so the attributes which don't read anything don't query for `dyn AttributeRead`.
+#[allow(clippy::type_complexity)]
+fn refresh_dirty_attr_reading<A: Attribute>(
+    mut attrs: Query<
+        (
+            Entity,
+            &mut A,
+            Option<&dyn ModifierGeneric<A>>,
+            Option<&dyn AttributeRead<A>>,
+        ),
+        With<DirtyAttr<A>>,
+    >,
+    anchors: Res<ModifierAnchors<A>>,
+    mut commands: Commands,
+) {
+    for (dirty, attr, mods, reads) in attrs.iter_mut() {
+        refresh_attr(attr, mods, ModifierCtx { reads }, &anchors);
+        commands.get_entity(dirty).unwrap().remove::<DirtyAttr<A>>();
+    }
+}
+
+fn refresh_attr<A: Attribute>(
+    mut attr: Mut<A>,
+    mods: Option<ReadTraits<'_, dyn ModifierGeneric<A>>>,
+    ctx: ModifierCtx<'_, A>,
+    anchors: &ModifierAnchors<A>,
+) {
+    debug!("some modifiers have changed!");
+    let mut mods: Vec<_> = mods.map_or_else(Vec::new, |mods| {
+        mods.iter()
+            .map(|modifier| (anchors.index_of(&modifier.priority()), modifier))
+            .collect()
+    });
+    mods.sort_unstable_by(|a, b| {
+        let order = a.0.cmp(&b.0);
+        #[cfg(debug_assertions)]
+        if order.is_eq() && (a.1.is_order_indepedent() || b.1.is_order_indepedent()) {
+            warn!(
+                "ambiguity between the order of two modifiers ({} and {} have the same priority)",
+                a.1.type_name(),
+                b.1.type_name(),
+            );
+        }
+        order
+    });
+
+    // bucket grouped modifiers, keeping them sorted within each bucket.
+    let mut groups: Vec<(_, Vec<_>)> = Vec::new();
+    for &(_, modifier) in mods.iter() {
+        let Some(group) = modifier.group() else {
+            continue;
+        };
+        match groups.iter_mut().find(|(id, _)| *id == group) {
+            Some((_, bucket)) => bucket.push(modifier),
+            None => groups.push((group, vec![modifier])),
+        }
+    }
+
+    let snapshot = attr.snapshot();
+    // change detection is only triggered once we know the new value is significant.
+    let value = attr.bypass_change_detection();
+
+    Reset::reset(value);
+
+    for (_, modifier) in mods.iter() {
+        let Some(group) = modifier.group() else {
+            modifier.apply_with(value, &ctx);
+            continue;
+        };
+        // each group is applied once, at the position of its earliest modifier.
+        if let Some(index) = groups.iter().position(|(id, _)| *id == group) {
+            let (_, bucket) = groups.swap_remove(index);
+            (group.combine)(&bucket, value, &ctx);
+        }
+    }
+
+    let significant = match snapshot {
+        Some(snapshot) if !(snapshot.recompute_is_significant)(value, &snapshot.previous) => {
+            *value = snapshot.previous;
+            false
+        }
+        _ => true,
+    };
+    if significant {
+        attr.set_changed();
+    }
+}
+
+impl<A: Attribute> Plugin for AttributePlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ModifierAnchors<A>>()
+            .add_startup_system(resolve_modifier_anchors::<A>);
+
+        let mut refresh = attribute_refresh(app);
+        refresh.reads.insert(
+            TypeId::of::<A>(),
+            self.reads
+                .iter()
+                .map(|read| (read.type_id, read.type_name))
+                .collect(),
+        );
+        if let Some(cycle) = refresh.read_cycle::<A>() {
+            panic!("attributes can't read each other in a cycle ({})", cycle.join(" reads "));
+        }
+
+        for read in &self.reads {
+            (read.register)(app);
+        }
+        let refresh_system = || {
+            let refresh_system = if self.reads.is_empty() {
+                refresh_dirty_attr::<A>.into_descriptor()
+            } else {
+                // labelled like `refresh_dirty_attr`, so the systems ordered around it don't need to know the difference.
+                refresh_dirty_attr_reading::<A>.label(refresh_dirty_attr::<A>.as_system_label())
+            };
+            self.reads
+                .iter()
+                .fold(refresh_system, |refresh, read| (read.refresh_after)(refresh))
+        };
+
+        let check = DirtyCheck {
+            is_dirty: is_dirty::<A>,
+            type_name: std::any::type_name::<A>(),
+        };
+        let mut refresh = attribute_refresh(app);
+        refresh
+            .stage
+            .add_system(refresh_system().with_run_criteria(refresh_once::<A>));
+        refresh
+            .refresh
+            .add_system(refresh_system().with_run_criteria(refresh_until_stable::<A>));
+        refresh.attributes.push(check);
+        if let Some(max_iterations) = self.max_iterations {
+            refresh.stable_checks.push((check, max_iterations));
+            app.world
+                .resource_mut::<RefreshIteration>()
+                .max_iterations
+                .insert(TypeId::of::<A>(), max_iterations);
+        }
+    }
+}
+
+/// Registers the required information for a [`ModifierGeneric`].
+///
+/// The relevant [`AttributePlugin`] should also be added to your app.
+///
+/// A modifier which implements [`ModifierGeneric`] for several attributes
+/// needs one plugin for each attribute it should modify.
+///
+/// # Examples
+/// ```rust
+/// use std::marker::PhantomData;
+///
+/// use bevy::prelude::*;
+/// use bevy_attr::{Attribute, AttributePlugin, ModifierGeneric, ModifierGenericPlugin, ModifierPriority};
+///
+/// trait Element: Send + Sync + 'static {}
+///
+/// struct Fire;
+/// impl Element for Fire {}
+///
+/// struct Ice;
+/// impl Element for Ice {}
+///
+/// #[derive(Component)]
+/// struct Resistance<E: Element> {
+///     value: usize,
+///     _marker: PhantomData<E>,
+/// }
+///
+/// impl<E: Element> Default for Resistance<E> {
+///     fn default() -> Self {
+///         Self {
+///             value: 0,
+///             _marker: PhantomData,
+///         }
+///     }
+/// }
+///
+/// impl<E: Element> Attribute for Resistance<E> {}
+///
+/// // armor resists every element.
+/// #[derive(Component)]
+/// struct Armor(usize);
+///
+/// impl<E: Element> ModifierGeneric<Resistance<E>> for Armor {
+///     fn priority(&self) -> ModifierPriority<Resistance<E>> {
+///         ModifierPriority::ZERO
+///     }
+///
+///     fn apply(&self, resistance: &mut Resistance<E>) {
+///         resistance.value += self.0;
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(MinimalPlugins);
+///
+/// app.add_plugin(AttributePlugin::<Resistance<Fire>>::default())
+///     .add_plugin(AttributePlugin::<Resistance<Ice>>::default())
+///     .add_plugin(ModifierGenericPlugin::<Armor, Resistance<Fire>>::default())
+///     .add_plugin(ModifierGenericPlugin::<Armor, Resistance<Ice>>::default());
+///
+/// let id = app
+///     .world
+///     .spawn((Resistance::<Fire>::default(), Resistance::<Ice>::default(), Armor(5)))
+///     .id();
+///
+/// app.update();
+/// app.update();
+///
+/// assert_eq!(app.world.get::<Resistance<Fire>>(id).unwrap().value, 5);
+/// assert_eq!(app.world.get::<Resistance<Ice>>(id).unwrap().value, 5);
+/// ```
+pub struct ModifierGenericPlugin<M: ModifierGeneric<A>, A: Attribute>(PhantomData<(M, A)>);
+
+impl<M: ModifierGeneric<A>, A: Attribute> Default for ModifierGenericPlugin<M, A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Marker component to indicates that an [`Attribute`]'s modifiers have changed since the last update.
+#[derive(Component)]
+pub struct DirtyAttr<A: Attribute>(PhantomData<A>);
+
+impl<A: Attribute> Default for DirtyAttr<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
 }
 
 fn modifier_changed<M: ModifierGeneric<A> + Component, A: Attribute>(
@@ -408,8 +1496,14 @@ fn modifier_changed<M: ModifierGeneric<A> + Component, A: Attribute>(
 
 fn modifier_removed<M: ModifierGeneric<A> + Component, A: Attribute>(
     removed: RemovedComponents<M>,
+    iteration: Res<RefreshIteration>,
     mut commands: Commands,
 ) {
+    // removals are only cleared at the end of the frame, so later iterations would see the same removals again.
+    // attributes are first marked dirty in either the usual pass or the first iteration after it.
+    if iteration.iteration > 1 {
+        return;
+    }
     for entity in &removed {
         #[cfg(debug_assertions)]
         trace!(
@@ -424,16 +1518,62 @@ fn modifier_removed<M: ModifierGeneric<A> + Component, A: Attribute>(
     }
 }
 
+#[allow(clippy::type_complexity)]
+fn read_changed<B: Attribute, A: Attribute>(
+    changed: Query<Entity, (Changed<B>, With<A>, Without<DirtyAttr<A>>)>,
+    mut commands: Commands,
+) {
+    for entity in &changed {
+        #[cfg(debug_assertions)]
+        trace!(
+            "attribute {} read by {} changed on {:?}",
+            std::any::type_name::<B>(),
+            std::any::type_name::<A>(),
+            entity
+        );
+        commands.entity(entity).insert(DirtyAttr::<A>::default());
+    }
+}
+
+fn read_removed<B: Attribute, A: Attribute>(
+    removed: RemovedComponents<B>,
+    iteration: Res<RefreshIteration>,
+    mut commands: Commands,
+) {
+    // see `modifier_removed`.
+    if iteration.iteration > 1 {
+        return;
+    }
+    for entity in &removed {
+        #[cfg(debug_assertions)]
+        trace!(
+            "attribute {} read by {} removed from {:?}",
+            std::any::type_name::<B>(),
+            std::any::type_name::<A>(),
+            entity
+        );
+        let Some(mut commands) = commands.get_entity(entity) else {
+            continue;
+        };
+        commands.insert(DirtyAttr::<A>::default());
+    }
+}
+
 impl<M: ModifierGeneric<A> + Component, A: Attribute> Plugin for ModifierGenericPlugin<M, A> {
     fn build(&self, app: &mut App) {
-        app.add_system_set_to_stage(
-            CoreStage::PostUpdate,
+        let systems = || {
             SystemSet::new()
-                .before(refresh_dirty_attr::<A>)
                 .with_system(modifier_changed::<M, A>)
-                .with_system(modifier_removed::<M, A>),
-        );
+                .with_system(modifier_removed::<M, A>)
+        };
+        attribute_refresh(app).add_detect_systems::<A>(systems(), systems());
         app.register_component_as::<dyn ModifierGeneric<A>, M>();
+        if let Some(priority) = M::shared_priority() {
+            app.world
+                .get_resource_or_insert_with(ModifierAnchors::<A>::default)
+                .modifiers
+                .push((std::any::type_name::<M>(), priority));
+        }
     }
 }
 
@@ -443,4 +1583,293 @@ impl<M: ModifierGeneric<A> + Component, A: Attribute> Plugin for ModifierGeneric
 pub type ModifierPlugin<M> = ModifierGenericPlugin<M, <M as Modifier>::Attr>;
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[derive(Component, Deref, DerefMut, Default, Debug, PartialEq)]
+    pub(crate) struct Ping(u32);
+
+    impl Attribute for Ping {}
+
+    #[derive(Component, Deref, DerefMut, Default, Debug, PartialEq)]
+    pub(crate) struct Pong(u32);
+
+    impl Attribute for Pong {}
+
+    // when both are attributes, each modifies the other, so recomputing either one always dirties the other.
+    impl Modifier for Ping {
+        type Attr = Pong;
+
+        const PRIORITY: ModifierPriority<Pong> = ModifierPriority::ZERO;
+
+        fn apply(&self, pong: &mut Pong) {
+            **pong += **self + 1;
+        }
+    }
+
+    impl Modifier for Pong {
+        type Attr = Ping;
+
+        const PRIORITY: ModifierPriority<Ping> = ModifierPriority::ZERO;
+
+        fn apply(&self, ping: &mut Ping) {
+            **ping += **self + 1;
+        }
+    }
+
+    #[derive(Component, Deref, DerefMut, Clone, Default, Debug, PartialEq)]
+    struct Speed(f32);
+
+    impl Attribute for Speed {
+        fn snapshot(&self) -> Option<Snapshot<Self>> {
+            Some(Snapshot::new(self.clone(), |speed, previous| {
+                (**speed - **previous).abs() > 0.5
+            }))
+        }
+    }
+
+    #[derive(Component)]
+    struct Boost(f32);
+
+    impl Modifier for Boost {
+        type Attr = Speed;
+
+        const PRIORITY: ModifierPriority<Speed> = ModifierPriority::ZERO;
+
+        fn apply(&self, speed: &mut Speed) {
+            **speed += self.0;
+        }
+    }
+
+    #[derive(Component, Deref, DerefMut, Default, Debug, PartialEq)]
+    struct Stride(f32);
+
+    impl Attribute for Stride {}
+
+    #[derive(Component)]
+    struct Gait;
+
+    impl Modifier for Gait {
+        type Attr = Stride;
+
+        const PRIORITY: ModifierPriority<Stride> = ModifierPriority::ZERO;
+
+        fn apply(&self, _: &mut Stride) {}
+
+        fn apply_with(&self, stride: &mut Stride, ctx: &ModifierCtx<'_, Stride>) {
+            **stride = ctx.get::<Speed>().map_or(0., |speed| **speed * 2.);
+        }
+    }
+
+    #[derive(Resource, Default, Clone, Copy, Debug, PartialEq)]
+    struct Changes {
+        speed: usize,
+        stride: usize,
+    }
+
+    fn count_changes(
+        speed: Query<(), Changed<Speed>>,
+        stride: Query<(), Changed<Stride>>,
+        mut changes: ResMut<Changes>,
+    ) {
+        changes.speed += speed.iter().count();
+        changes.stride += stride.iter().count();
+    }
+
+    #[test]
+    fn insignificant_recomputes_are_discarded() {
+        let mut app = App::new();
+        app.init_resource::<Changes>()
+            .add_plugin(AttributePlugin::<Speed>::default())
+            .add_plugin(AttributePlugin::<Stride>::default().reads::<Speed>())
+            .add_plugin(ModifierPlugin::<Boost>::default())
+            .add_plugin(ModifierPlugin::<Gait>::default())
+            .add_system_to_stage(CoreStage::Last, count_changes);
+
+        let id = app
+            .world
+            .spawn((Speed::default(), Boost(1.), Stride::default(), Gait))
+            .id();
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(app.world.get::<Speed>(id), Some(&Speed(1.)));
+        assert_eq!(app.world.get::<Stride>(id), Some(&Stride(2.)));
+
+        let changes = *app.world.resource::<Changes>();
+        app.world.get_mut::<Boost>(id).unwrap().0 = 1.25;
+        for _ in 0..4 {
+            app.update();
+        }
+        // the old value is kept, and neither `Speed` nor `Stride` (which reads it) is changed.
+        assert_eq!(app.world.get::<Speed>(id), Some(&Speed(1.)));
+        assert_eq!(app.world.get::<Stride>(id), Some(&Stride(2.)));
+        assert_eq!(*app.world.resource::<Changes>(), changes);
+
+        app.world.get_mut::<Boost>(id).unwrap().0 = 3.;
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(app.world.get::<Speed>(id), Some(&Speed(3.)));
+        assert_eq!(app.world.get::<Stride>(id), Some(&Stride(6.)));
+        assert_eq!(
+            *app.world.resource::<Changes>(),
+            Changes {
+                speed: changes.speed + 1,
+                stride: changes.stride + 1,
+            }
+        );
+    }
+
+    #[derive(Component)]
+    struct Shield;
+
+    impl Modifier for Shield {
+        type Attr = Ping;
+
+        const PRIORITY: ModifierPriority<Ping> = ModifierPriority::anchor("shield");
+
+        fn apply(&self, _: &mut Ping) {}
+    }
+
+    fn anchored_app(anchors: Vec<(&'static str, ModifierPriority<Ping>)>) -> App {
+        let mut app = App::new();
+        app.add_plugin(AttributePlugin::<Ping>::default());
+        for (name, priority) in anchors {
+            app.add_modifier_anchor(name, priority);
+        }
+        app
+    }
+
+    #[test]
+    fn anchors_resolve_in_any_order() {
+        let mut app = anchored_app(vec![
+            ("armor", ModifierPriority::anchor("base").after()),
+            ("armor", ModifierPriority::ZERO.after().after()),
+            ("base", ModifierPriority::ZERO.after()),
+        ]);
+        app.update();
+
+        let anchors = app.world.resource::<ModifierAnchors<Ping>>();
+        assert_eq!(anchors.index_of(&ModifierPriority::anchor("armor").before()), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "modifier anchor `armor` was registered with incompatible priorities")]
+    fn conflicting_anchors_panic() {
+        anchored_app(vec![
+            ("armor", ModifierPriority::ZERO.after()),
+            ("armor", ModifierPriority::ZERO.before()),
+        ])
+        .update();
+    }
+
+    #[test]
+    #[should_panic(expected = "modifier anchor `base` was never registered")]
+    fn missing_anchors_panic() {
+        anchored_app(vec![("armor", ModifierPriority::anchor("base").after())]).update();
+    }
+
+    #[test]
+    #[should_panic(expected = "modifier anchor `shield` was never registered (used by modifier")]
+    fn missing_modifier_anchors_panic() {
+        let mut app = anchored_app(Vec::new());
+        app.add_plugin(ModifierPlugin::<Shield>::default());
+        app.update();
+    }
+
+    #[test]
+    #[should_panic(expected = "modifier anchor `armor` is defined in terms of itself")]
+    fn cyclic_anchors_panic() {
+        anchored_app(vec![
+            ("armor", ModifierPriority::anchor("base")),
+            ("base", ModifierPriority::anchor("armor").after()),
+        ])
+        .update();
+    }
+
+    #[test]
+    fn unanchored_priorities_are_ordered() {
+        let zero = ModifierPriority::<Ping>::ZERO;
+        assert!(ModifierPriority::<Ping>::ZERO.before() < zero);
+        assert!(ModifierPriority::<Ping>::ZERO.after() > zero);
+        assert_eq!(ModifierPriority::<Ping>::anchor("armor").partial_cmp(&zero), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attributes can't read each other in a cycle")]
+    fn cyclic_reads_panic() {
+        App::new()
+            .add_plugin(AttributePlugin::<Ping>::default().reads::<Pong>())
+            .add_plugin(AttributePlugin::<Pong>::default().reads::<Ping>());
+    }
+
+    fn run_refresh(app: &mut App) -> Result<(), Vec<&'static str>> {
+        app.world
+            .resource_scope(|world, mut refresh: Mut<AttributeRefresh>| refresh.run(world))
+    }
+
+    #[test]
+    fn until_stable_settles_a_change_in_one_iteration() {
+        let mut app = App::new();
+        app.add_plugin(AttributePlugin::<Ping>::default().until_stable(1))
+            .add_plugin(ModifierPlugin::<Pong>::default());
+
+        let id = app.world.spawn((Ping::default(), Pong(1))).id();
+        assert_eq!(run_refresh(&mut app), Ok(()));
+        assert_eq!(app.world.get::<Ping>(id), Some(&Ping(2)));
+
+        app.world.get_mut::<Pong>(id).unwrap().0 = 2;
+        assert_eq!(run_refresh(&mut app), Ok(()));
+        assert_eq!(app.world.get::<Ping>(id), Some(&Ping(3)));
+    }
+
+    #[test]
+    fn until_stable_leaves_other_attributes_alone() {
+        let mut app = App::new();
+        app.add_plugin(AttributePlugin::<Ping>::default().until_stable(2))
+            .add_plugin(AttributePlugin::<Speed>::default())
+            .add_plugin(ModifierPlugin::<Pong>::default())
+            .add_plugin(ModifierPlugin::<Boost>::default());
+
+        let id = app
+            .world
+            .spawn((Ping::default(), Pong(1), Speed::default(), Boost(1.)))
+            .id();
+        app.update();
+        assert_eq!(app.world.get::<Ping>(id), Some(&Ping(2)));
+        // one frame to notice the new modifier, and one to refresh `Speed`.
+        assert_eq!(app.world.get::<Speed>(id), Some(&Speed(0.)));
+        app.update();
+        assert_eq!(app.world.get::<Speed>(id), Some(&Speed(1.)));
+    }
+
+    #[test]
+    fn until_stable_keeps_each_attributes_maximum() {
+        let mut app = App::new();
+        app.add_plugin(AttributePlugin::<Ping>::default().until_stable(2))
+            .add_plugin(AttributePlugin::<Pong>::default().until_stable(8))
+            .add_plugin(ModifierPlugin::<Ping>::default())
+            .add_plugin(ModifierPlugin::<Pong>::default());
+
+        let id = app.world.spawn((Ping::default(), Pong::default())).id();
+        // `Pong` settles once `Ping` stops being refreshed.
+        let dirty = run_refresh(&mut app).unwrap_err();
+        assert_eq!(dirty, [std::any::type_name::<Ping>()]);
+        assert!(app.world.get::<DirtyAttr<Ping>>(id).is_some());
+        assert!(app.world.get::<DirtyAttr<Pong>>(id).is_none());
+    }
+
+    #[test]
+    fn until_stable_gives_up_on_cycles() {
+        let mut app = App::new();
+        app.add_plugin(AttributePlugin::<Ping>::default().until_stable(8))
+            .add_plugin(AttributePlugin::<Pong>::default().until_stable(8))
+            .add_plugin(ModifierPlugin::<Ping>::default())
+            .add_plugin(ModifierPlugin::<Pong>::default());
+
+        app.world.spawn((Ping::default(), Pong::default()));
+        let dirty = run_refresh(&mut app).unwrap_err();
+        assert_eq!(dirty, [std::any::type_name::<Ping>(), std::any::type_name::<Pong>()]);
+    }
+}
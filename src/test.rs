@@ -0,0 +1,250 @@
+//! Utilities for testing attributes and modifiers.
+//!
+//! Requires the `test-util` feature.
+
+use core::fmt;
+
+use bevy::prelude::*;
+
+use crate::{
+    Attribute, AttributePlugin, AttributeRefresh, Modifier, ModifierGeneric,
+    ModifierGenericPlugin, ModifierPlugin,
+};
+
+/// A wrapper around an [`App`] which keeps updating until every attribute has been recomputed.
+///
+/// Attributes may take several updates to settle, especially when modifiers are themselves attributes.
+/// Rather than calling [`App::update`] a fixed number of times,
+/// [`settle`] updates until no [`DirtyAttr`] remains for any attribute with an [`AttributePlugin`],
+/// however the plugin was added.
+///
+/// The [`App`] is accessible through [`Deref`][std::ops::Deref] and [`DerefMut`][std::ops::DerefMut].
+///
+/// # Examples
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_attr::{test::AttrTestApp, Attribute, Modifier, ModifierPriority};
+///
+/// #[derive(Component, Deref, DerefMut, Debug, PartialEq)]
+/// struct MaxHealth(usize);
+///
+/// impl Default for MaxHealth {
+///     fn default() -> Self {
+///         Self(100)
+///     }
+/// }
+///
+/// impl Attribute for MaxHealth {}
+///
+/// #[derive(Component)]
+/// struct ExtraMaxHealth(usize);
+///
+/// impl Modifier for ExtraMaxHealth {
+///     type Attr = MaxHealth;
+///
+///     const PRIORITY: ModifierPriority<MaxHealth> = ModifierPriority::ZERO;
+///
+///     fn apply(&self, value: &mut MaxHealth) {
+///         **value += self.0;
+///     }
+/// }
+///
+/// let mut app = AttrTestApp::new();
+/// app.add_attribute::<MaxHealth>()
+///     .add_modifier::<ExtraMaxHealth>();
+///
+/// let id = app.spawn(MaxHealth::default());
+/// app.assert_attr(id, MaxHealth(100));
+///
+/// app.set_modifier(id, ExtraMaxHealth(50));
+/// app.assert_attr(id, MaxHealth(150));
+///
+/// app.remove_modifier::<ExtraMaxHealth>(id);
+/// app.assert_attr(id, MaxHealth(100));
+/// ```
+///
+/// [`settle`]: AttrTestApp::settle
+/// [`DirtyAttr`]: crate::DirtyAttr
+pub struct AttrTestApp {
+    app: App,
+    max_updates: usize,
+}
+
+impl AttrTestApp {
+    /// The default number of updates [`settle`][AttrTestApp::settle] may run before panicking.
+    pub const DEFAULT_MAX_UPDATES: usize = 64;
+
+    /// Creates a new app with the [`MinimalPlugins`].
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        Self {
+            app,
+            max_updates: Self::DEFAULT_MAX_UPDATES,
+        }
+    }
+
+    /// Sets the number of updates [`settle`][AttrTestApp::settle] may run before panicking.
+    pub fn with_max_updates(mut self, max_updates: usize) -> Self {
+        self.max_updates = max_updates;
+        self
+    }
+
+    /// Adds the default [`AttributePlugin`] for `A`.
+    pub fn add_attribute<A: Attribute>(&mut self) -> &mut Self {
+        self.add_attribute_plugin(AttributePlugin::<A>::default())
+    }
+
+    /// Adds an [`AttributePlugin`].
+    pub fn add_attribute_plugin<A: Attribute>(&mut self, plugin: AttributePlugin<A>) -> &mut Self {
+        self.app.add_plugin(plugin);
+        self
+    }
+
+    /// Adds the [`ModifierPlugin`] for `M`.
+    pub fn add_modifier<M: Modifier + Component>(&mut self) -> &mut Self {
+        self.app.add_plugin(ModifierPlugin::<M>::default());
+        self
+    }
+
+    /// Adds the [`ModifierGenericPlugin`] for `M` on `A`.
+    pub fn add_modifier_generic<M: ModifierGeneric<A> + Component, A: Attribute>(
+        &mut self,
+    ) -> &mut Self {
+        self.app.add_plugin(ModifierGenericPlugin::<M, A>::default());
+        self
+    }
+
+    /// Spawns an entity with the given bundle.
+    pub fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        self.app.world.spawn(bundle).id()
+    }
+
+    /// Inserts a modifier onto `entity`, replacing any existing modifier of the same type.
+    pub fn set_modifier<M: Component>(&mut self, entity: Entity, modifier: M) -> &mut Self {
+        self.app.world.entity_mut(entity).insert(modifier);
+        self
+    }
+
+    /// Removes a modifier from `entity`.
+    pub fn remove_modifier<M: Component>(&mut self, entity: Entity) -> &mut Self {
+        self.app.world.entity_mut(entity).remove::<M>();
+        self
+    }
+
+    fn dirty_attrs(&mut self) -> Vec<&'static str> {
+        let world = &mut self.app.world;
+        // every `AttributePlugin` records its attribute here when it is built.
+        let checks = match world.get_resource::<AttributeRefresh>() {
+            Some(refresh) => refresh.attributes.clone(),
+            None => return Vec::new(),
+        };
+        checks
+            .iter()
+            .filter(|check| (check.is_dirty)(world))
+            .map(|check| check.type_name)
+            .collect()
+    }
+
+    /// Updates the app until every attribute has been recomputed.
+    ///
+    /// Always runs at least one update, and finishes after an update which both started and ended
+    /// with no [`DirtyAttr`][crate::DirtyAttr] on any entity.
+    ///
+    /// # Panics
+    /// Panics if the attributes have not settled after the maximum number of updates
+    /// (see [`with_max_updates`][AttrTestApp::with_max_updates]),
+    /// which usually indicates a loop between modifiers.
+    pub fn settle(&mut self) -> &mut Self {
+        let mut dirty = self.dirty_attrs();
+        // an update which cleans up everything may still have dirtied something which is only noticed next update,
+        // so one extra update confirms the attributes really settled.
+        for update in 0..=self.max_updates {
+            let was_dirty = !dirty.is_empty();
+            if update == self.max_updates && was_dirty {
+                break;
+            }
+            self.app.update();
+
+            dirty = self.dirty_attrs();
+            if !was_dirty && dirty.is_empty() {
+                return self;
+            }
+        }
+        panic!(
+            "attributes did not settle after {} updates (still dirty: {dirty:?})",
+            self.max_updates,
+        );
+    }
+
+    /// Settles the app and returns the value of the attribute `A` on `entity`.
+    ///
+    /// # Panics
+    /// Panics if the attributes do not settle or `entity` has no `A`.
+    pub fn attr<A: Attribute>(&mut self, entity: Entity) -> &A {
+        self.settle();
+        self.app.world.get::<A>(entity).unwrap_or_else(|| {
+            panic!(
+                "{entity:?} has no attribute {}",
+                std::any::type_name::<A>()
+            )
+        })
+    }
+
+    /// Settles the app and asserts that the attribute `A` on `entity` is equal to `expected`.
+    ///
+    /// # Panics
+    /// Panics if the attributes do not settle, `entity` has no `A`, or the values differ.
+    pub fn assert_attr<A: Attribute + PartialEq + fmt::Debug>(&mut self, entity: Entity, expected: A) {
+        let actual = self.attr::<A>(entity);
+        assert_eq!(
+            actual,
+            &expected,
+            "unexpected value for attribute {} on {entity:?}",
+            std::any::type_name::<A>(),
+        );
+    }
+}
+
+impl Default for AttrTestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::ops::Deref for AttrTestApp {
+    type Target = App;
+
+    fn deref(&self) -> &App {
+        &self.app
+    }
+}
+
+impl std::ops::DerefMut for AttrTestApp {
+    fn deref_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AttrTestApp;
+    use crate::{
+        tests::{Ping, Pong},
+        AttributePlugin, ModifierPlugin,
+    };
+
+    #[test]
+    #[should_panic(expected = "attributes did not settle after 8 updates")]
+    fn settle_panics_when_modifiers_keep_dirtying() {
+        let mut app = AttrTestApp::new().with_max_updates(8);
+        // added through the inner `App`, rather than `AttrTestApp::add_attribute`.
+        app.add_plugin(AttributePlugin::<Ping>::default())
+            .add_plugin(AttributePlugin::<Pong>::default())
+            .add_plugin(ModifierPlugin::<Ping>::default())
+            .add_plugin(ModifierPlugin::<Pong>::default());
+
+        app.spawn((Ping::default(), Pong::default()));
+        app.settle();
+    }
+}